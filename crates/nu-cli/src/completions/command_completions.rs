@@ -1,6 +1,7 @@
 use crate::completions::{
+    completion_options::sort_suggestions,
     file_completions::file_path_completion, Completer, CompletionOptions, Matcher,
-    matcher::{FuzzyMatcher, MatchScore, TextMatcher}
+    matcher::{FuzzyMatcher, MatchScore, PrefixMatcher, TextMatcher}
 };
 use nu_parser::{trim_quotes, FlatShape};
 use nu_protocol::{
@@ -191,9 +192,17 @@ impl Completer for CommandCompletion {
             })
             .last();
 
-        let matcher = match completion_options.matcher {
-            Matcher::Prefix => todo!(),
-            Matcher::Fuzzy => FuzzyMatcher::new(),
+        let prefix_str = String::from_utf8_lossy(&prefix).to_string();
+
+        // Smart case: an all-lowercase needle matches case-insensitively, but as soon
+        // as the needle contains an uppercase letter we respect the configured
+        // case sensitivity literally.
+        let case_sensitive =
+            completion_options.case_sensitive && prefix_str.chars().any(|c| c.is_uppercase());
+
+        let matcher: Box<dyn TextMatcher> = match completion_options.matcher {
+            Matcher::Prefix => Box::new(PrefixMatcher::new(case_sensitive)),
+            Matcher::Fuzzy => Box::new(FuzzyMatcher::new(case_sensitive)),
         };
 
         // The last item here would be the earliest shape that could possible by part of this subcommand
@@ -213,7 +222,7 @@ impl Completer for CommandCompletion {
         };
 
         if !subcommands.is_empty() {
-            return subcommands;
+            return sort_suggestions(&prefix_str, subcommands, completion_options.sort_by);
         }
 
         let commands = if matches!(self.flat_shape, nu_parser::FlatShape::External)
@@ -287,7 +296,7 @@ impl Completer for CommandCompletion {
             .chain(commands.into_iter())
             .collect::<Vec<_>>();
 
-        output
+        sort_suggestions(&prefix_str, output, completion_options.sort_by)
     }
 
     // Replace base filter with no filter once all the results are already based in the current path
@@ -1,3 +1,5 @@
+use reedline::Suggestion;
+
 #[derive(Clone)]
 pub enum SortBy {
     LevenshteinDistance,
@@ -5,6 +7,54 @@ pub enum SortBy {
     None,
 }
 
+/// Order completion suggestions according to `sort_by`.
+///
+/// `LevenshteinDistance` sorts by edit distance to `needle` (closest first), breaking
+/// ties by the `score` already stored on each `Suggestion` (highest first);
+/// `Ascending` sorts lexicographically by `value`, and `None` leaves the
+/// suggestions in whatever order they were collected.
+pub fn sort_suggestions(needle: &str, mut items: Vec<Suggestion>, sort_by: SortBy) -> Vec<Suggestion> {
+    match sort_by {
+        SortBy::LevenshteinDistance => {
+            items.sort_by(|a, b| {
+                let a_distance = levenshtein_distance(needle, &a.value);
+                let b_distance = levenshtein_distance(needle, &b.value);
+                a_distance.cmp(&b_distance).then_with(|| b.score.cmp(&a.score))
+            });
+        }
+        SortBy::Ascending => {
+            items.sort_by(|a, b| a.value.cmp(&b.value));
+        }
+        SortBy::None => {}
+    }
+
+    items
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev = row[0];
+        row[0] = i;
+
+        for j in 1..=b.len() {
+            let cur = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j - 1])
+            };
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
 #[derive(Clone)]
 pub enum Matcher {
     Prefix,
@@ -1,4 +1,4 @@
-use crate::completions::{Completer, CompletionOptions};
+use crate::completions::{completion_options::sort_suggestions, Completer, CompletionOptions};
 use nu_engine::eval_call;
 use nu_protocol::{
     ast::{Argument, Call, Expr, Expression},
@@ -55,9 +55,9 @@ impl CustomCompletion {
 impl Completer for CustomCompletion {
     fn fetch(
         &mut self,
-        _: CompletionOptions,
+        completion_options: CompletionOptions,
         _: &StateWorkingSet,
-        _: Vec<u8>,
+        prefix: Vec<u8>,
         span: Span,
         offset: usize,
         pos: usize,
@@ -65,6 +65,44 @@ impl Completer for CustomCompletion {
         // Line position
         let line_pos = pos - offset;
 
+        // Row/column of the cursor within the full (possibly multi-line) buffer,
+        // derived by counting newlines up to `line_pos`.
+        let mut row = 0i64;
+        let mut column = 0i64;
+        for ch in self.line[..line_pos.min(self.line.len())].chars() {
+            if ch == '\n' {
+                row += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+
+        let zero_span = Span { start: 0, end: 0 };
+        let string_expr = |val: String| Expression {
+            span: zero_span,
+            ty: Type::String,
+            expr: Expr::String(val),
+            custom_completion: None,
+        };
+        let int_expr = |val: i64| Expression {
+            span: zero_span,
+            ty: Type::Int,
+            expr: Expr::Int(val),
+            custom_completion: None,
+        };
+        let context = Expression {
+            span: zero_span,
+            ty: Type::Any,
+            expr: Expr::Record(vec![
+                (string_expr("line".to_string()), string_expr(self.line.clone())),
+                (string_expr("pos".to_string()), int_expr(line_pos as i64)),
+                (string_expr("row".to_string()), int_expr(row)),
+                (string_expr("column".to_string()), int_expr(column)),
+            ]),
+            custom_completion: None,
+        };
+
         // Call custom declaration
         let result = eval_call(
             &self.engine_state,
@@ -73,18 +111,9 @@ impl Completer for CustomCompletion {
                 decl_id: self.decl_id,
                 head: span,
                 arguments: vec![
-                    Argument::Positional(Expression {
-                        span: Span { start: 0, end: 0 },
-                        ty: Type::String,
-                        expr: Expr::String(self.line.clone()),
-                        custom_completion: None,
-                    }),
-                    Argument::Positional(Expression {
-                        span: Span { start: 0, end: 0 },
-                        ty: Type::Int,
-                        expr: Expr::Int(line_pos as i64),
-                        custom_completion: None,
-                    }),
+                    Argument::Positional(string_expr(self.line.clone())),
+                    Argument::Positional(int_expr(line_pos as i64)),
+                    Argument::Positional(context),
                 ],
                 redirect_stdout: true,
                 redirect_stderr: true,
@@ -119,8 +148,7 @@ impl Completer for CustomCompletion {
             _ => vec![],
         };
 
-        // TODO: what to do with CompletionOptions here?
-
-        suggestions
+        let prefix = String::from_utf8_lossy(&prefix).to_string();
+        sort_suggestions(&prefix, suggestions, completion_options.sort_by)
     }
 }
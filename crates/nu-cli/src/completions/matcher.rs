@@ -9,20 +9,58 @@ pub struct MatchScore(pub i32);
 
 pub struct FuzzyMatcher {
     matcher: SkimMatcherV2,
+    case_sensitive: bool,
 }
 
 impl FuzzyMatcher {
-    pub fn new() -> Self {
+    pub fn new(case_sensitive: bool) -> Self {
         Self {
             matcher: SkimMatcherV2::default(),
+            case_sensitive,
         }
     }
 }
 
 impl TextMatcher for FuzzyMatcher {
     fn matches(&self, haystack: &str, needle: &str) -> Option<MatchScore> {
-        self.matcher
-            .fuzzy_indices(haystack, needle)
-            .map(|(score, _)| MatchScore(score as i32))
+        if self.case_sensitive {
+            self.matcher
+                .fuzzy_indices(haystack, needle)
+                .map(|(score, _)| MatchScore(score as i32))
+        } else {
+            self.matcher
+                .fuzzy_indices(&haystack.to_ascii_lowercase(), &needle.to_ascii_lowercase())
+                .map(|(score, _)| MatchScore(score as i32))
+        }
+    }
+}
+
+pub struct PrefixMatcher {
+    case_sensitive: bool,
+}
+
+impl PrefixMatcher {
+    pub fn new(case_sensitive: bool) -> Self {
+        Self { case_sensitive }
+    }
+}
+
+impl TextMatcher for PrefixMatcher {
+    fn matches(&self, haystack: &str, needle: &str) -> Option<MatchScore> {
+        let starts_with = if self.case_sensitive {
+            haystack.starts_with(needle)
+        } else {
+            haystack
+                .to_ascii_lowercase()
+                .starts_with(&needle.to_ascii_lowercase())
+        };
+
+        if starts_with {
+            // Score higher the closer the haystack's length is to the needle's,
+            // so an exact match always outscores a longer one sharing the prefix.
+            Some(MatchScore(-((haystack.len() - needle.len()) as i32)))
+        } else {
+            None
+        }
     }
 }
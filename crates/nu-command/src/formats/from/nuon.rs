@@ -0,0 +1,633 @@
+use nu_protocol::ast::{Call, RangeInclusion};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, Range, ShellError, Signature, Span, Value,
+};
+
+#[derive(Clone)]
+pub struct FromNuon;
+
+impl Command for FromNuon {
+    fn name(&self) -> &str {
+        "from nuon"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from nuon").category(Category::Experimental)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert from Nuon (Nushell Object Notation) text into a table."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, ShellError> {
+        let span = call.head;
+        let string_input = input.collect_string("", span)?;
+
+        Ok(from_nuon(&string_input, span)?.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Converts nuon formatted string to table",
+            example: "'[1, 2, 3]' | from nuon",
+            result: Some(Value::List {
+                vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+/// Tokens produced from the raw nuon text, before being assembled into `Value`s.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Semicolon,
+    Comma,
+    Colon,
+    DotDot,
+    DotDotLess,
+    Atom(String),
+}
+
+fn tokenize(input: &str, span: Span) -> Result<Vec<(Token, Span)>, ShellError> {
+    let mut tokens = vec![];
+    let chars: Vec<char> = input.chars().collect();
+    // Byte offset of each char, so tokens can carry a real `Span` into the source
+    // instead of everyone sharing the whole-input span.
+    let byte_offsets: Vec<usize> = input.char_indices().map(|(i, _)| i).collect();
+    let total_len = input.len();
+    let mut idx = 0;
+
+    let byte_at = |i: usize| -> usize {
+        byte_offsets.get(i).copied().unwrap_or(total_len)
+    };
+
+    macro_rules! push_span {
+        ($tok:expr, $start:expr, $end:expr) => {
+            tokens.push((
+                $tok,
+                Span {
+                    start: span.start + byte_at($start),
+                    end: span.start + byte_at($end),
+                },
+            ))
+        };
+    }
+
+    while idx < chars.len() {
+        let c = chars[idx];
+
+        match c {
+            c if c.is_whitespace() => idx += 1,
+            // `0x[AABB]` binary literal: must be lexed as a single atom before the
+            // generic `[`/`]` handling below, or it shreds into `0x`, `[`, `AABB`, `]`.
+            '0' if chars.get(idx + 1) == Some(&'x') && chars.get(idx + 2) == Some(&'[') => {
+                let start = idx;
+                idx += 3;
+                while idx < chars.len() && chars[idx] != ']' {
+                    idx += 1;
+                }
+                if idx >= chars.len() {
+                    return Err(ShellError::UnsupportedInput(
+                        "unterminated binary literal in nuon input".to_string(),
+                        span,
+                    ));
+                }
+                idx += 1; // consume the closing ']'
+                push_span!(Token::Atom(chars[start..idx].iter().collect()), start, idx);
+            }
+            '{' => {
+                push_span!(Token::LeftBrace, idx, idx + 1);
+                idx += 1;
+            }
+            '}' => {
+                push_span!(Token::RightBrace, idx, idx + 1);
+                idx += 1;
+            }
+            '[' => {
+                push_span!(Token::LeftBracket, idx, idx + 1);
+                idx += 1;
+            }
+            ']' => {
+                push_span!(Token::RightBracket, idx, idx + 1);
+                idx += 1;
+            }
+            ';' => {
+                push_span!(Token::Semicolon, idx, idx + 1);
+                idx += 1;
+            }
+            ',' => {
+                push_span!(Token::Comma, idx, idx + 1);
+                idx += 1;
+            }
+            ':' => {
+                push_span!(Token::Colon, idx, idx + 1);
+                idx += 1;
+            }
+            '"' => {
+                let start = idx;
+                let mut s = String::new();
+                idx += 1;
+                loop {
+                    match chars.get(idx) {
+                        Some('"') => {
+                            idx += 1;
+                            break;
+                        }
+                        Some('\\') => {
+                            idx += 1;
+                            match chars.get(idx) {
+                                Some('n') => s.push('\n'),
+                                Some('t') => s.push('\t'),
+                                Some('r') => s.push('\r'),
+                                Some('"') => s.push('"'),
+                                Some('\\') => s.push('\\'),
+                                Some(other) => s.push(*other),
+                                None => {
+                                    return Err(ShellError::UnsupportedInput(
+                                        "unterminated escape in nuon string".to_string(),
+                                        span,
+                                    ))
+                                }
+                            }
+                            idx += 1;
+                        }
+                        Some(other) => {
+                            s.push(*other);
+                            idx += 1;
+                        }
+                        None => {
+                            return Err(ShellError::UnsupportedInput(
+                                "unterminated string in nuon input".to_string(),
+                                span,
+                            ))
+                        }
+                    }
+                }
+                push_span!(Token::Atom(format!("\"{}\"", s)), start, idx);
+            }
+            '.' if chars.get(idx + 1) == Some(&'.') => {
+                let start = idx;
+                if chars.get(idx + 2) == Some(&'<') {
+                    idx += 3;
+                    push_span!(Token::DotDotLess, start, idx);
+                } else {
+                    idx += 2;
+                    push_span!(Token::DotDot, start, idx);
+                }
+            }
+            _ => {
+                let start = idx;
+                // Note `:` is deliberately NOT a stop char here: the only place a bare
+                // `:` appears in nuon output is right after a quoted record key (its own
+                // token), so letting it through lets RFC3339 dates like
+                // `2020-01-01T00:00:00+00:00` tokenize as a single atom.
+                while idx < chars.len()
+                    && !chars[idx].is_whitespace()
+                    && !matches!(chars[idx], '{' | '}' | '[' | ']' | ';' | ',')
+                    && !(chars[idx] == '.' && chars.get(idx + 1) == Some(&'.'))
+                {
+                    idx += 1;
+                }
+                push_span!(Token::Atom(chars[start..idx].iter().collect()), start, idx);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [(Token, Span)],
+    pos: usize,
+    /// Fallback span covering the whole input, used for constructed `Value` nodes and
+    /// for errors when there's no token to point at (e.g. unexpected end of input).
+    span: Span,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn span_at(&self, pos: usize) -> Span {
+        self.tokens.get(pos).map(|(_, s)| *s).unwrap_or(self.span)
+    }
+
+    /// Span of the token about to be consumed (or the fallback span at end of input).
+    fn current_span(&self) -> Span {
+        self.span_at(self.pos)
+    }
+
+    /// Span of the token most recently consumed by `next()`.
+    fn last_span(&self) -> Span {
+        if self.pos == 0 {
+            self.span
+        } else {
+            self.span_at(self.pos - 1)
+        }
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let tok = self.tokens.get(self.pos).map(|(t, _)| t);
+        self.pos += 1;
+        tok
+    }
+
+    fn expect(&mut self, tok: Token) -> Result<(), ShellError> {
+        match self.next() {
+            Some(found) if *found == tok => Ok(()),
+            found => Err(ShellError::UnsupportedInput(
+                format!("expected {:?}, found {:?}", tok, found),
+                self.last_span(),
+            )),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, ShellError> {
+        match self.peek() {
+            Some(Token::LeftBrace) => self.parse_record(),
+            Some(Token::LeftBracket) => self.parse_list_or_table(),
+            Some(Token::Atom(_)) => self.parse_atom(),
+            other => Err(ShellError::UnsupportedInput(
+                format!("expected a value, found {:?}", other),
+                self.current_span(),
+            )),
+        }
+    }
+
+    fn parse_record(&mut self) -> Result<Value, ShellError> {
+        self.expect(Token::LeftBrace)?;
+
+        let mut cols = vec![];
+        let mut vals = vec![];
+
+        while !matches!(self.peek(), Some(Token::RightBrace)) {
+            let col = match self.next() {
+                Some(Token::Atom(s)) => unquote(s),
+                other => {
+                    return Err(ShellError::UnsupportedInput(
+                        format!("expected a column name, found {:?}", other),
+                        self.last_span(),
+                    ))
+                }
+            };
+            self.expect(Token::Colon)?;
+            let val = self.parse_value()?;
+
+            cols.push(col);
+            vals.push(val);
+
+            if matches!(self.peek(), Some(Token::Comma)) {
+                self.next();
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        Ok(Value::Record {
+            cols,
+            vals,
+            span: self.span,
+        })
+    }
+
+    fn parse_value_list(&mut self) -> Result<Vec<Value>, ShellError> {
+        let mut vals = vec![];
+
+        while !matches!(self.peek(), Some(Token::RightBracket) | Some(Token::Semicolon)) {
+            vals.push(self.parse_value()?);
+
+            if matches!(self.peek(), Some(Token::Comma)) {
+                self.next();
+            }
+        }
+
+        Ok(vals)
+    }
+
+    /// Parses the `[c1, c2]` header row of a table literal. Header cells are column
+    /// names (bare or quoted words, e.g. `to nuon`'s `headers.join(", ")`), not values,
+    /// so they must be read as atoms directly rather than through `parse_value`/
+    /// `atom_to_value` — a bareword like `a` has no valid value form and would
+    /// otherwise fail to parse.
+    fn parse_header_list(&mut self) -> Result<Vec<String>, ShellError> {
+        let mut headers = vec![];
+
+        while !matches!(self.peek(), Some(Token::RightBracket)) {
+            match self.next() {
+                Some(Token::Atom(s)) => headers.push(unquote(s)),
+                other => {
+                    return Err(ShellError::UnsupportedInput(
+                        format!("expected a column name, found {:?}", other),
+                        self.last_span(),
+                    ))
+                }
+            }
+
+            if matches!(self.peek(), Some(Token::Comma)) {
+                self.next();
+            }
+        }
+
+        Ok(headers)
+    }
+
+    fn parse_list_or_table(&mut self) -> Result<Value, ShellError> {
+        self.expect(Token::LeftBracket)?;
+
+        // Table literal: [[c1, c2]; [v1, v2], [...]]
+        if matches!(self.peek(), Some(Token::LeftBracket)) {
+            let save = self.pos;
+            self.next();
+            // A header row holds only column names; an inner list holding anything else
+            // (records, lists, ranges) is an ordinary nested list and is rewound below.
+            let headers = self.parse_header_list().ok();
+            if headers.is_some() {
+                self.expect(Token::RightBracket)?;
+            }
+
+            if let (Some(headers), Some(Token::Semicolon)) = (headers, self.peek().cloned()) {
+                self.next();
+
+                let mut rows = vec![];
+                loop {
+                    self.expect(Token::LeftBracket)?;
+                    let row_span = self.current_span();
+                    let row = self.parse_value_list()?;
+                    self.expect(Token::RightBracket)?;
+
+                    if row.len() != headers.len() {
+                        return Err(ShellError::UnsupportedInput(
+                            format!(
+                                "table row has {} column(s), expected {} to match the header row",
+                                row.len(),
+                                headers.len()
+                            ),
+                            row_span,
+                        ));
+                    }
+
+                    rows.push(Value::Record {
+                        cols: headers.clone(),
+                        vals: row,
+                        span: self.span,
+                    });
+
+                    if matches!(self.peek(), Some(Token::Comma)) {
+                        self.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                self.expect(Token::RightBracket)?;
+
+                return Ok(Value::List {
+                    vals: rows,
+                    span: self.span,
+                });
+            }
+
+            // Wasn't actually a table literal, rewind and parse as a plain list.
+            self.pos = save;
+        }
+
+        let vals = self.parse_value_list()?;
+        self.expect(Token::RightBracket)?;
+
+        Ok(Value::List {
+            vals,
+            span: self.span,
+        })
+    }
+
+    fn parse_atom(&mut self) -> Result<Value, ShellError> {
+        let atom_span = self.current_span();
+        let atom = match self.next() {
+            Some(Token::Atom(s)) => s.clone(),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("expected a value, found {:?}", other),
+                    self.last_span(),
+                ))
+            }
+        };
+
+        let from = atom_to_value(&atom, atom_span)?;
+
+        match self.peek() {
+            Some(Token::DotDot) | Some(Token::DotDotLess) => {
+                let inclusion = if matches!(self.peek(), Some(Token::DotDotLess)) {
+                    RangeInclusion::RightExclusive
+                } else {
+                    RangeInclusion::Inclusive
+                };
+                self.next();
+                let to = self.parse_atom()?;
+
+                Ok(Value::Range {
+                    val: Box::new(Range {
+                        from,
+                        incr: Value::test_int(1),
+                        to,
+                        inclusion,
+                    }),
+                    span: self.span,
+                })
+            }
+            _ => Ok(from),
+        }
+    }
+}
+
+fn unquote(s: &str) -> String {
+    if s.starts_with('"') && s.ends_with('"') && s.len() >= 2 {
+        s[1..s.len() - 1].to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+fn atom_to_value(atom: &str, span: Span) -> Result<Value, ShellError> {
+    if atom.starts_with('"') {
+        return Ok(Value::String {
+            val: unquote(atom),
+            span,
+        });
+    }
+
+    if let Some(hex) = atom.strip_prefix("0x[").and_then(|s| s.strip_suffix(']')) {
+        let mut val = vec![];
+        for pair in hex.as_bytes().chunks(2) {
+            let byte_str = std::str::from_utf8(pair).unwrap_or_default();
+            let byte = u8::from_str_radix(byte_str, 16).map_err(|_| {
+                ShellError::UnsupportedInput("invalid binary literal in nuon".to_string(), span)
+            })?;
+            val.push(byte);
+        }
+        return Ok(Value::Binary { val, span });
+    }
+
+    match atom {
+        "true" => return Ok(Value::test_bool(true)),
+        "false" => return Ok(Value::test_bool(false)),
+        "$nothing" => return Ok(Value::Nothing { span }),
+        _ => {}
+    }
+
+    if let Some(ns) = atom.strip_suffix("ns") {
+        if let Ok(val) = ns.parse::<i64>() {
+            return Ok(Value::Duration { val, span });
+        }
+    }
+
+    if let Some(bytes) = atom.strip_suffix('b') {
+        if let Ok(val) = bytes.parse::<i64>() {
+            return Ok(Value::Filesize { val, span });
+        }
+    }
+
+    if let Ok(val) = atom.parse::<i64>() {
+        return Ok(Value::Int { val, span });
+    }
+
+    if let Ok(val) = atom.parse::<f64>() {
+        return Ok(Value::Float { val, span });
+    }
+
+    if let Ok(val) = chrono::DateTime::parse_from_rfc3339(atom) {
+        return Ok(Value::Date { val, span });
+    }
+
+    Err(ShellError::UnsupportedInput(
+        format!("could not parse nuon value: {}", atom),
+        span,
+    ))
+}
+
+fn from_nuon(input: &str, span: Span) -> Result<Value, ShellError> {
+    let tokens = tokenize(input, span)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        span,
+    };
+
+    let value = parser.parse_value()?;
+
+    if parser.pos != tokens.len() {
+        let trailing_span = parser.current_span();
+        return Err(ShellError::UnsupportedInput(
+            "trailing input after nuon value".to_string(),
+            trailing_span,
+        ));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Value {
+        from_nuon(input, Span::test_data()).expect("expected nuon input to parse")
+    }
+
+    #[test]
+    fn round_trips_record() {
+        // Mirrors what `to nuon` emits for a record: `"col": val` pairs.
+        match parse("{\"a\": 1, \"b\": 2}") {
+            Value::Record { cols, vals, .. } => {
+                assert_eq!(cols, vec!["a".to_string(), "b".to_string()]);
+                assert_eq!(vals.len(), 2);
+            }
+            _ => panic!("expected a record"),
+        }
+    }
+
+    #[test]
+    fn round_trips_plain_list() {
+        match parse("[1, 2, 3]") {
+            Value::List { vals, .. } => assert_eq!(vals.len(), 3),
+            _ => panic!("expected a list"),
+        }
+    }
+
+    #[test]
+    fn round_trips_table() {
+        // `to nuon` emits bare header words, not quoted strings or values.
+        match parse("[[a, b]; [1, 2], [3, 4]]") {
+            Value::List { vals, .. } => {
+                assert_eq!(vals.len(), 2);
+                match &vals[0] {
+                    Value::Record { cols, vals, .. } => {
+                        assert_eq!(cols, &vec!["a".to_string(), "b".to_string()]);
+                        assert_eq!(vals.len(), 2);
+                    }
+                    _ => panic!("expected a record row"),
+                }
+            }
+            _ => panic!("expected a list"),
+        }
+    }
+
+    #[test]
+    fn table_row_arity_mismatch_errors() {
+        match from_nuon("[[a, b]; [1, 2, 3]]", Span::test_data()) {
+            Err(ShellError::UnsupportedInput(_, _)) => {}
+            other => panic!("expected a row arity error, got {}", other.is_ok()),
+        }
+    }
+
+    #[test]
+    fn round_trips_range() {
+        match parse("1..10") {
+            Value::Range { .. } => {}
+            _ => panic!("expected a range"),
+        }
+    }
+
+    #[test]
+    fn round_trips_binary() {
+        match parse("0x[AABB]") {
+            Value::Binary { val, .. } => assert_eq!(val, vec![0xAA, 0xBB]),
+            _ => panic!("expected binary"),
+        }
+    }
+
+    #[test]
+    fn round_trips_duration_and_filesize() {
+        match parse("42ns") {
+            Value::Duration { val, .. } => assert_eq!(val, 42),
+            _ => panic!("expected a duration"),
+        }
+
+        match parse("42b") {
+            Value::Filesize { val, .. } => assert_eq!(val, 42),
+            _ => panic!("expected a filesize"),
+        }
+    }
+
+    #[test]
+    fn round_trips_date() {
+        // `to nuon` emits dates via `to_rfc3339()`, which includes a UTC offset and
+        // no space, e.g. `2020-01-01T00:00:00+00:00`.
+        match parse("2020-01-01T00:00:00+00:00") {
+            Value::Date { .. } => {}
+            _ => panic!("expected a date"),
+        }
+    }
+}
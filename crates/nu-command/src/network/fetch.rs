@@ -7,7 +7,8 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::RawStream;
 
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
 };
 use reqwest::blocking::Response;
 
@@ -58,11 +59,50 @@ impl Command for SubCommand {
                 "custom headers you want to add ",
                 Some('H'),
             )
+            .named(
+                "max-redirects",
+                SyntaxShape::Int,
+                "the maximum number of redirects to follow before erroring out",
+                None,
+            )
+            .switch(
+                "no-redirect",
+                "do not follow redirects; return the redirect response itself",
+                None,
+            )
+            .named(
+                "proxy",
+                SyntaxShape::String,
+                "the URL of an HTTP, HTTPS, or SOCKS proxy to route the request through",
+                None,
+            )
+            .switch(
+                "insecure",
+                "do not verify the server's TLS certificate",
+                Some('k'),
+            )
+            .named(
+                "cacert",
+                SyntaxShape::String,
+                "path to a PEM-encoded certificate to additionally trust",
+                None,
+            )
+            .named(
+                "range",
+                SyntaxShape::String,
+                "byte range to request, e.g. 'bytes=0-1023' (use --full to see if it was honored)",
+                None,
+            )
             .switch(
                 "raw",
                 "fetch contents as text rather than a table",
                 Some('r'),
             )
+            .switch(
+                "full",
+                "returns the status, headers and body of the response as a record",
+                Some('f'),
+            )
             .filter()
             .category(Category::Network)
     }
@@ -72,7 +112,9 @@ impl Command for SubCommand {
     }
 
     fn extra_usage(&self) -> &str {
-        "Performs HTTP GET operation."
+        "Performs HTTP GET operation. When --range is given, the server may ignore it and \
+         return the full body with a 200 status instead of a 206 Partial Content; use --full \
+         to inspect the response status and confirm the range was honored."
     }
 
     fn run(
@@ -102,6 +144,31 @@ impl Command for SubCommand {
                 example: "fetch -H [my-header-key my-header-value] url.com",
                 result: None,
             },
+            Example {
+                description: "Fetch content from a data: URL without making a network request",
+                example: "fetch data:text/plain,hello",
+                result: None,
+            },
+            Example {
+                description: "Fetch content from a local file through the same pipeline as a URL",
+                example: "fetch file:///home/user/data.json",
+                result: None,
+            },
+            Example {
+                description: "Fetch the status, headers and body of url.com",
+                example: "fetch --full url.com",
+                result: None,
+            },
+            Example {
+                description: "Fetch url.com through a corporate proxy, trusting its custom CA",
+                example: "fetch --proxy http://proxy.example.com:8080 --cacert corp-ca.pem url.com",
+                result: None,
+            },
+            Example {
+                description: "Fetch the first 1024 bytes of a large file",
+                example: "fetch --range 'bytes=0-1023' --full url.com/big-file.iso",
+                result: None,
+            },
         ]
     }
 }
@@ -109,10 +176,17 @@ impl Command for SubCommand {
 struct Arguments {
     url: Option<Value>,
     raw: bool,
+    full: bool,
     user: Option<String>,
     password: Option<String>,
     timeout: Option<Value>,
     headers: Option<Value>,
+    max_redirects: Option<i64>,
+    no_redirect: bool,
+    proxy: Option<String>,
+    insecure: bool,
+    cacert: Option<String>,
+    range: Option<String>,
 }
 
 fn run_fetch(
@@ -124,10 +198,17 @@ fn run_fetch(
     let args = Arguments {
         url: Some(call.req(engine_state, stack, 0)?),
         raw: call.has_flag("raw"),
+        full: call.has_flag("full"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
         timeout: call.get_flag(engine_state, stack, "timeout")?,
         headers: call.get_flag(engine_state, stack, "headers")?,
+        max_redirects: call.get_flag(engine_state, stack, "max-redirects")?,
+        no_redirect: call.has_flag("no-redirect"),
+        proxy: call.get_flag(engine_state, stack, "proxy")?,
+        insecure: call.has_flag("insecure"),
+        cacert: call.get_flag(engine_state, stack, "cacert")?,
+        range: call.get_flag(engine_state, stack, "range")?,
     };
     helper(engine_state, stack, call, args)
 }
@@ -151,6 +232,15 @@ fn helper(
 
     let span = url_value.span()?;
     let requested_url = url_value.as_string()?;
+
+    if requested_url.starts_with("data:") {
+        return request_data_url(&requested_url, args.raw, engine_state, stack, span);
+    }
+
+    if requested_url.starts_with("file://") {
+        return request_file_url(&requested_url, args.raw, engine_state, stack, span);
+    }
+
     let url = match url::Url::parse(&requested_url) {
         Ok(u) => u,
         Err(_e) => {
@@ -166,13 +256,21 @@ fn helper(
     let timeout = args.timeout;
     let headers = args.headers;
     let raw = args.raw;
+    let full = args.full;
     let login = match (user, password) {
         (Some(user), Some(password)) => Some(encode(&format!("{}:{}", user, password))),
         (Some(user), _) => Some(encode(&format!("{}:", user))),
         _ => None,
     };
 
-    let client = http_client();
+    let client = http_client(
+        args.max_redirects,
+        args.no_redirect,
+        args.proxy,
+        args.insecure,
+        args.cacert,
+        span,
+    )?;
     let mut request = client.get(url);
 
     if let Some(timeout) = timeout {
@@ -191,6 +289,10 @@ fn helper(
         request = request.header("Authorization", format!("Basic {}", login));
     }
 
+    if let Some(range) = args.range {
+        request = request.header("Range", range);
+    }
+
     if let Some(headers) = headers {
         let mut custom_headers: HashMap<String, Value> = HashMap::new();
 
@@ -242,73 +344,23 @@ fn helper(
     }
 
     match request.send() {
-        Ok(resp) => match resp.headers().get("content-type") {
-            Some(content_type) => {
-                let content_type = content_type.to_str().map_err(|e| {
-                    ShellError::GenericError(
-                        e.to_string(),
-                        "".to_string(),
-                        None,
-                        Some("MIME type were invalid".to_string()),
-                        Vec::new(),
-                    )
-                })?;
-                let content_type = mime::Mime::from_str(content_type).map_err(|_| {
-                    ShellError::GenericError(
-                        format!("MIME type unknown: {}", content_type),
-                        "".to_string(),
-                        None,
-                        Some("given unknown MIME type".to_string()),
-                        Vec::new(),
-                    )
-                })?;
-                let ext = match (content_type.type_(), content_type.subtype()) {
-                    (mime::TEXT, mime::PLAIN) => {
-                        let path_extension = url::Url::parse(&requested_url)
-                            .map_err(|_| {
-                                ShellError::GenericError(
-                                    format!("Cannot parse URL: {}", requested_url),
-                                    "".to_string(),
-                                    None,
-                                    Some("cannot parse".to_string()),
-                                    Vec::new(),
-                                )
-                            })?
-                            .path_segments()
-                            .and_then(|segments| segments.last())
-                            .and_then(|name| if name.is_empty() { None } else { Some(name) })
-                            .and_then(|name| {
-                                PathBuf::from(name)
-                                    .extension()
-                                    .map(|name| name.to_string_lossy().to_string())
-                            });
-                        path_extension
-                    }
-                    _ => Some(content_type.subtype().to_string()),
-                };
-
-                let output = response_to_buffer(resp, engine_state, span);
+        Ok(resp) => {
+            if full {
+                let status = resp.status().as_u16() as i64;
+                let headers = headers_to_record(resp.headers(), span);
+                let body = convert_response(resp, engine_state, stack, span, &requested_url, raw)?
+                    .into_value(span);
 
-                if raw {
-                    return Ok(output);
-                }
-
-                if let Some(ext) = ext {
-                    match engine_state.find_decl(format!("from {}", ext).as_bytes(), &[]) {
-                        Some(converter_id) => engine_state.get_decl(converter_id).run(
-                            engine_state,
-                            stack,
-                            &Call::new(span),
-                            output,
-                        ),
-                        None => Ok(output),
-                    }
-                } else {
-                    Ok(output)
+                Ok(Value::Record {
+                    cols: vec!["status".to_string(), "headers".to_string(), "body".to_string()],
+                    vals: vec![Value::Int { val: status, span }, headers, body],
+                    span,
                 }
+                .into_pipeline_data())
+            } else {
+                convert_response(resp, engine_state, stack, span, &requested_url, raw)
             }
-            None => Ok(response_to_buffer(resp, engine_state, span)),
-        },
+        }
         Err(e) if e.is_timeout() => Err(ShellError::NetworkFailure(
             format!("Request to {} has timed out", requested_url),
             span,
@@ -354,17 +406,229 @@ fn helper(
     }
 }
 
+// Picks the `from {ext}` converter based on the response's `content-type` and runs the
+// body through it, the same way a plain (non-`--full`) fetch always has.
+fn convert_response(
+    resp: Response,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+    requested_url: &str,
+    raw: bool,
+) -> Result<PipelineData, ShellError> {
+    match resp.headers().get("content-type") {
+        Some(content_type) => {
+            let content_type = content_type.to_str().map_err(|e| {
+                ShellError::GenericError(
+                    e.to_string(),
+                    "".to_string(),
+                    None,
+                    Some("MIME type were invalid".to_string()),
+                    Vec::new(),
+                )
+            })?;
+            let content_type = mime::Mime::from_str(content_type).map_err(|_| {
+                ShellError::GenericError(
+                    format!("MIME type unknown: {}", content_type),
+                    "".to_string(),
+                    None,
+                    Some("given unknown MIME type".to_string()),
+                    Vec::new(),
+                )
+            })?;
+            let ext = match (content_type.type_(), content_type.subtype()) {
+                (mime::TEXT, mime::PLAIN) => {
+                    let path_extension = url::Url::parse(requested_url)
+                        .map_err(|_| {
+                            ShellError::GenericError(
+                                format!("Cannot parse URL: {}", requested_url),
+                                "".to_string(),
+                                None,
+                                Some("cannot parse".to_string()),
+                                Vec::new(),
+                            )
+                        })?
+                        .path_segments()
+                        .and_then(|segments| segments.last())
+                        .and_then(|name| if name.is_empty() { None } else { Some(name) })
+                        .and_then(|name| {
+                            PathBuf::from(name)
+                                .extension()
+                                .map(|name| name.to_string_lossy().to_string())
+                        });
+                    path_extension
+                }
+                _ => Some(content_type.subtype().to_string()),
+            };
+
+            let output = response_to_buffer(resp, engine_state, span);
+
+            if raw {
+                return Ok(output);
+            }
+
+            if let Some(ext) = ext {
+                match engine_state.find_decl(format!("from {}", ext).as_bytes(), &[]) {
+                    Some(converter_id) => engine_state.get_decl(converter_id).run(
+                        engine_state,
+                        stack,
+                        &Call::new(span),
+                        output,
+                    ),
+                    None => Ok(output),
+                }
+            } else {
+                Ok(output)
+            }
+        }
+        None => Ok(response_to_buffer(resp, engine_state, span)),
+    }
+}
+
+// Builds the `headers` record for `fetch --full`: one column per header name, mapping
+// to its value as a string.
+fn headers_to_record(headers: &reqwest::header::HeaderMap, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for (name, value) in headers.iter() {
+        cols.push(name.to_string());
+        vals.push(Value::String {
+            val: value.to_str().unwrap_or_default().to_string(),
+            span,
+        });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
 fn response_to_buffer(
     response: Response,
     engine_state: &EngineState,
     span: Span,
 ) -> nu_protocol::PipelineData {
-    let buffered_input = BufReader::new(response);
+    reader_to_buffer(BufReader::new(response), engine_state, span)
+}
 
+// Shared by the HTTP response and local file sources so they both flow through the same
+// `from {ext}` auto-conversion machinery; `request_data_url` builds its stream inline.
+fn reader_to_buffer<R>(reader: R, engine_state: &EngineState, span: Span) -> nu_protocol::PipelineData
+where
+    R: std::io::Read + Send + 'static,
+{
     PipelineData::ExternalStream {
+        stdout: Some(RawStream::new(
+            Box::new(BufferedReader { input: reader }),
+            engine_state.ctrlc.clone(),
+            span,
+        )),
+        stderr: None,
+        exit_code: None,
+        span,
+        metadata: None,
+    }
+}
+
+// Opens a local file for `file://` URLs and converts it the same way the `text/plain`
+// branch of the HTTP path does: guessing the `from {ext}` converter from the file name.
+fn request_file_url(
+    requested_url: &str,
+    raw: bool,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    let url = url::Url::parse(requested_url).map_err(|_| {
+        ShellError::UnsupportedInput(
+            "Incomplete or incorrect url. Expected a full url, e.g., file:///path/to/file"
+                .to_string(),
+            span,
+        )
+    })?;
+
+    let path = url.to_file_path().map_err(|_| {
+        ShellError::UnsupportedInput(
+            format!("Could not resolve a local path from {:?}", requested_url),
+            span,
+        )
+    })?;
+
+    let file = std::fs::File::open(&path).map_err(|e| {
+        ShellError::NetworkFailure(format!("Could not open {}: {}", path.display(), e), span)
+    })?;
+
+    let output = reader_to_buffer(BufReader::new(file), engine_state, span);
+
+    if raw {
+        return Ok(output);
+    }
+
+    let ext = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_string());
+
+    match ext {
+        Some(ext) => match engine_state.find_decl(format!("from {}", ext).as_bytes(), &[]) {
+            Some(converter_id) => {
+                engine_state
+                    .get_decl(converter_id)
+                    .run(engine_state, stack, &Call::new(span), output)
+            }
+            None => Ok(output),
+        },
+        None => Ok(output),
+    }
+}
+
+// Handles `data:` URLs entirely in-process, without going through `reqwest`.
+// Format: data:[<mediatype>][;base64],<data>
+fn request_data_url(
+    requested_url: &str,
+    raw: bool,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    let without_scheme = &requested_url["data:".len()..];
+    let (meta, data) = without_scheme.split_once(',').ok_or_else(|| {
+        ShellError::UnsupportedInput(
+            "data URL is missing the ',' separator between metadata and payload".to_string(),
+            span,
+        )
+    })?;
+
+    let (mediatype, is_base64) = match meta.strip_suffix(";base64") {
+        Some(mediatype) => (mediatype, true),
+        None => (meta, false),
+    };
+    let mediatype = if mediatype.is_empty() {
+        "text/plain;charset=US-ASCII"
+    } else {
+        mediatype
+    };
+
+    let bytes = if is_base64 {
+        base64::decode(data).map_err(|e| {
+            ShellError::UnsupportedInput(format!("invalid base64 in data URL: {}", e), span)
+        })?
+    } else {
+        percent_decode(data)
+    };
+
+    let content_type = mime::Mime::from_str(mediatype).map_err(|_| {
+        ShellError::GenericError(
+            format!("MIME type unknown: {}", mediatype),
+            "".to_string(),
+            None,
+            Some("given unknown MIME type".to_string()),
+            Vec::new(),
+        )
+    })?;
+
+    let output = PipelineData::ExternalStream {
         stdout: Some(RawStream::new(
             Box::new(BufferedReader {
-                input: buffered_input,
+                input: BufReader::new(std::io::Cursor::new(bytes)),
             }),
             engine_state.ctrlc.clone(),
             span,
@@ -373,15 +637,126 @@ fn response_to_buffer(
         exit_code: None,
         span,
         metadata: None,
+    };
+
+    if raw {
+        return Ok(output);
+    }
+
+    let ext = match (content_type.type_(), content_type.subtype()) {
+        (mime::TEXT, mime::PLAIN) => None,
+        _ => Some(content_type.subtype().to_string()),
+    };
+
+    match ext {
+        Some(ext) => match engine_state.find_decl(format!("from {}", ext).as_bytes(), &[]) {
+            Some(converter_id) => {
+                engine_state
+                    .get_decl(converter_id)
+                    .run(engine_state, stack, &Call::new(span), output)
+            }
+            None => Ok(output),
+        },
+        None => Ok(output),
     }
 }
 
-// Only panics if the user agent is invalid but we define it statically so either
-// it always or never fails
-#[allow(clippy::unwrap_used)]
-fn http_client() -> reqwest::blocking::Client {
-    reqwest::blocking::Client::builder()
+// Minimal percent-decoder for the non-base64 `data:` URL payload form.
+fn percent_decode(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' && idx + 2 < bytes.len() {
+            if let Ok(hex) = std::str::from_utf8(&bytes[idx + 1..idx + 3]) {
+                if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                    out.push(byte);
+                    idx += 3;
+                    continue;
+                }
+            }
+        }
+
+        out.push(bytes[idx]);
+        idx += 1;
+    }
+
+    out
+}
+
+fn http_client(
+    max_redirects: Option<i64>,
+    no_redirect: bool,
+    proxy: Option<String>,
+    insecure: bool,
+    cacert: Option<String>,
+    span: Span,
+) -> Result<reqwest::blocking::Client, ShellError> {
+    let redirect_policy = if no_redirect {
+        reqwest::redirect::Policy::none()
+    } else if let Some(max_redirects) = max_redirects {
+        reqwest::redirect::Policy::limited(max_redirects as usize)
+    } else {
+        reqwest::redirect::Policy::default()
+    };
+
+    // Transparently negotiate and decode gzip/brotli/deflate bodies: reqwest sends the
+    // matching `Accept-Encoding` header and decodes the stream as it's read, so
+    // `response_to_buffer` always sees plaintext and never observes `Content-Encoding`.
+    let mut builder = reqwest::blocking::Client::builder()
         .user_agent("nushell")
-        .build()
-        .unwrap()
+        .gzip(true)
+        .brotli(true)
+        .deflate(true)
+        .redirect(redirect_policy);
+
+    if let Some(proxy) = proxy {
+        let proxy = reqwest::Proxy::all(&proxy).map_err(|e| {
+            ShellError::GenericError(
+                format!("Invalid proxy URL: {}", proxy),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        builder = builder.proxy(proxy);
+    }
+
+    if insecure {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    if let Some(cacert) = cacert {
+        let pem = std::fs::read(&cacert).map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not read certificate file {}", cacert),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| {
+            ShellError::GenericError(
+                format!("Invalid certificate in {}", cacert),
+                e.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        builder = builder.add_root_certificate(cert);
+    }
+
+    builder.build().map_err(|e| {
+        ShellError::GenericError(
+            "Could not create the http client".to_string(),
+            e.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
 }